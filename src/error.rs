@@ -21,6 +21,7 @@ pub use crate::frame::Reason;
 #[derive(Debug)]
 pub struct Error {
     kind: Kind,
+    cause: Option<Box<dyn error::Error + Send + Sync>>,
 }
 
 #[derive(Debug)]
@@ -40,11 +41,36 @@ enum Kind {
 
     /// An `io::Error` occurred while trying to read or write.
     Io(Arc<io::Error>),
+
+    /// A pending item (e.g. a response future) was dropped before it was ever
+    /// processed.
+    Canceled,
+
+    /// An operation timed out.
+    Timeout,
 }
 
 // ===== impl Error =====
 
 impl Error {
+    fn new(kind: Kind) -> Self {
+        Error { kind, cause: None }
+    }
+
+    /// Attaches an underlying cause to this error, so that
+    /// [`Error::source`] can walk down to the original failure.
+    ///
+    /// The attached cause does not change the top-level `Display` message.
+    /// The `From` conversions and `from_io` do not call this, so errors built
+    /// by them have no cause and `source()` returns `None` for them.
+    pub(crate) fn with_cause<E>(mut self, cause: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        self.cause = Some(cause.into());
+        self
+    }
+
     /// If the error was caused by the remote peer, the error reason.
     ///
     /// This is either an error received by the peer or caused by an invalid
@@ -56,6 +82,78 @@ impl Error {
         }
     }
 
+    /// Returns true if the error was caused by a RST_STREAM frame being sent
+    /// or received.
+    pub fn is_reset(&self) -> bool {
+        matches!(self.kind, Kind::Reset(..))
+    }
+
+    /// Returns true if the error was caused by a GO_AWAY frame being sent or
+    /// received.
+    pub fn is_go_away(&self) -> bool {
+        matches!(self.kind, Kind::GoAway(..))
+    }
+
+    /// Returns true if the error was created from a bare [`Reason`].
+    pub fn is_reason(&self) -> bool {
+        matches!(self.kind, Kind::Reason(..))
+    }
+
+    /// Returns true if the error was caused by an invalid action taken by the
+    /// user of this library, or by a RST_STREAM or GO_AWAY that the user
+    /// initiated.
+    pub fn is_user(&self) -> bool {
+        match self.kind {
+            Kind::User(_) => true,
+            Kind::Reset(_, _, initiator) | Kind::GoAway(_, _, initiator) => {
+                matches!(initiator, Initiator::User)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if the error was initiated by the remote peer.
+    pub fn is_remote(&self) -> bool {
+        match self.kind {
+            Kind::Reset(_, _, initiator) | Kind::GoAway(_, _, initiator) => {
+                matches!(initiator, Initiator::Remote)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if a RST_STREAM or GO_AWAY error was initiated locally,
+    /// either by the library or by the user of this library. Returns false
+    /// for every other kind, including user errors.
+    pub fn is_local(&self) -> bool {
+        match self.kind {
+            Kind::Reset(_, _, initiator) | Kind::GoAway(_, _, initiator) => {
+                matches!(initiator, Initiator::User | Initiator::Library)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the stream id of the RST_STREAM that caused this error, if any.
+    pub fn stream_id(&self) -> Option<StreamId> {
+        match self.kind {
+            Kind::Reset(stream_id, _, _) => Some(stream_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the opaque debug data carried by a GO_AWAY frame, if any.
+    ///
+    /// The HTTP/2.0 spec allows a peer to attach arbitrary debug data to a
+    /// GO_AWAY frame. This returns that payload for the `GoAway` variant and
+    /// `None` otherwise.
+    pub fn debug_data(&self) -> Option<&Bytes> {
+        match self.kind {
+            Kind::GoAway(ref data, _, _) => Some(data),
+            _ => None,
+        }
+    }
+
     /// Returns the true if the error is an io::Error
     pub fn is_io(&self) -> bool {
         match self.kind {
@@ -81,9 +179,30 @@ impl Error {
     }
 
     pub(crate) fn from_io(err: io::Error) -> Self {
-        Error {
-            kind: Kind::Io(Arc::new(err)),
-        }
+        Error::new(Kind::Io(Arc::new(err)))
+    }
+
+    /// Creates a `Canceled` error, for a pending item dropped before it is
+    /// ever processed. The stream and connection code does not call this.
+    pub(crate) fn canceled() -> Self {
+        Error::new(Kind::Canceled)
+    }
+
+    /// Creates a `Timeout` error. The keepalive ping-pong code and the rest of
+    /// the connection code do not call this.
+    pub(crate) fn timeout() -> Self {
+        Error::new(Kind::Timeout)
+    }
+
+    /// Returns true if the error was caused by a pending item being dropped
+    /// before it was ever processed.
+    pub fn is_canceled(&self) -> bool {
+        matches!(self.kind, Kind::Canceled)
+    }
+
+    /// Returns true if the error was caused by an operation timing out.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.kind, Kind::Timeout)
     }
 }
 
@@ -91,23 +210,17 @@ impl From<proto::Error> for Error {
     fn from(src: proto::Error) -> Error {
         use crate::proto::Error::*;
 
-        Error {
-            kind: match src {
-                Reset(stream_id, reason, initiator) => Kind::Reset(stream_id, reason, initiator),
-                GoAway(debug_data, reason, initiator) => {
-                    Kind::GoAway(debug_data, reason, initiator)
-                }
-                Io(e) => Kind::Io(e),
-            },
-        }
+        Error::new(match src {
+            Reset(stream_id, reason, initiator) => Kind::Reset(stream_id, reason, initiator),
+            GoAway(debug_data, reason, initiator) => Kind::GoAway(debug_data, reason, initiator),
+            Io(e) => Kind::Io(e),
+        })
     }
 }
 
 impl From<Reason> for Error {
     fn from(src: Reason) -> Error {
-        Error {
-            kind: Kind::Reason(src),
-        }
+        Error::new(Kind::Reason(src))
     }
 }
 
@@ -122,9 +235,7 @@ impl From<SendError> for Error {
 
 impl From<UserError> for Error {
     fn from(src: UserError) -> Error {
-        Error {
-            kind: Kind::User(src),
-        }
+        Error::new(Kind::User(src))
     }
 }
 
@@ -144,8 +255,123 @@ impl fmt::Display for Error {
             Kind::Reason(reason) => write!(fmt, "protocol error: {}", reason),
             Kind::User(ref e) => write!(fmt, "user error: {}", e),
             Kind::Io(ref e) => e.fmt(fmt),
+            Kind::Canceled => write!(fmt, "operation was canceled"),
+            Kind::Timeout => write!(fmt, "operation timed out"),
         }
     }
 }
 
-impl error::Error for Error {}
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.cause
+            .as_ref()
+            .map(|cause| &**cause as &(dyn error::Error + 'static))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_preserves_originating_cause() {
+        // A reset built with `with_cause` carries the attached failure as its
+        // source.
+        let err = Error::new(Kind::Reset(
+            StreamId::from(1),
+            Reason::CANCEL,
+            Initiator::User,
+        ))
+        .with_cause(io::Error::new(io::ErrorKind::BrokenPipe, "boom"));
+
+        let source = error::Error::source(&err).expect("cause should be attached");
+        assert_eq!(source.to_string(), "boom");
+        // `Display` stays the top-level message only.
+        assert_eq!(err.to_string(), "stream reset by user: CANCEL");
+    }
+
+    #[test]
+    fn io_conversion_has_no_self_duplicate_cause() {
+        let err: Error =
+            proto::Error::Io(Arc::new(io::Error::new(io::ErrorKind::BrokenPipe, "boom"))).into();
+        // The io error is the error itself, not a nested cause.
+        assert!(error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn reset_predicates_and_stream_id() {
+        let remote = Error::new(Kind::Reset(
+            StreamId::from(1),
+            Reason::PROTOCOL_ERROR,
+            Initiator::Remote,
+        ));
+        assert!(remote.is_reset());
+        assert!(remote.is_remote());
+        assert!(!remote.is_local());
+        assert!(!remote.is_user());
+        assert_eq!(remote.stream_id(), Some(StreamId::from(1)));
+
+        let local = Error::new(Kind::Reset(
+            StreamId::from(3),
+            Reason::PROTOCOL_ERROR,
+            Initiator::User,
+        ));
+        assert!(local.is_local());
+        assert!(local.is_user());
+        assert!(!local.is_remote());
+
+        let library = Error::new(Kind::Reset(
+            StreamId::from(5),
+            Reason::PROTOCOL_ERROR,
+            Initiator::Library,
+        ));
+        assert!(library.is_local());
+        assert!(!library.is_user());
+    }
+
+    #[test]
+    fn debug_data_is_only_returned_for_go_away() {
+        let go_away = Error::new(Kind::GoAway(
+            Bytes::from_static(b"shutting down"),
+            Reason::NO_ERROR,
+            Initiator::Remote,
+        ));
+        assert!(go_away.is_go_away());
+        assert_eq!(go_away.debug_data().map(|b| &b[..]), Some(&b"shutting down"[..]));
+        assert_eq!(go_away.stream_id(), None);
+
+        let reset = Error::new(Kind::Reset(
+            StreamId::from(1),
+            Reason::PROTOCOL_ERROR,
+            Initiator::Remote,
+        ));
+        assert!(reset.debug_data().is_none());
+    }
+
+    #[test]
+    fn canceled_predicate() {
+        let err = Error::canceled();
+        assert!(err.is_canceled());
+        assert!(!err.is_timeout());
+        assert!(!err.is_reset());
+        assert!(err.reason().is_none());
+    }
+
+    #[test]
+    fn timeout_predicate() {
+        let err = Error::timeout();
+        assert!(err.is_timeout());
+        assert!(!err.is_canceled());
+        assert!(!err.is_io());
+    }
+
+    #[test]
+    fn io_timeout_is_not_reinterpreted() {
+        // A genuine transport timeout stays an io error; `Timeout` comes only
+        // from `Error::timeout()`.
+        let err = Error::from_io(io::Error::new(io::ErrorKind::TimedOut, "read"));
+        assert!(err.is_io());
+        assert!(!err.is_timeout());
+        assert!(err.into_io().is_some());
+    }
+}